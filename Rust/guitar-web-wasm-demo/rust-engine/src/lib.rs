@@ -44,8 +44,219 @@ impl Resonator {
     }
 }
 
+/// 4-point cubic Hermite interpolation over a circular buffer.
+///
+/// `buf[i]` is the current sample and `d` in `[0, 1)` is the fractional
+/// delay past it; `xm1`/`x1`/`x2` are the neighbouring taps. Much more
+/// accurate than a linear blend on short delay lines (high strings),
+/// where a linearly interpolated fractional delay otherwise detunes and
+/// dulls the tone.
+#[inline]
+fn cubic_hermite(buf: &[f32], i: usize, len: usize, d: f32) -> f32 {
+    let xm1 = buf[(i + len - 1) % len];
+    let x0 = buf[i];
+    let x1 = buf[(i + 1) % len];
+    let x2 = buf[(i + 2) % len];
+
+    let c0 = x0;
+    let c1 = 0.5 * (x1 - xm1);
+    let c2 = xm1 - 2.5 * x0 + 2.0 * x1 - 0.5 * x2;
+    let c3 = 0.5 * (x2 - xm1) + 1.5 * (x0 - x1);
+
+    ((c3 * d + c2) * d + c1) * d + c0
+}
+
+/// Single feedback comb filter, as used in the Freeverb/Schroeder topology.
+struct CombFilter {
+    buf: std::vec::Vec<f32>,
+    pos: usize,
+    filterstore: f32,
+    feedback: f32,
+    damp: f32,
+}
+
+impl CombFilter {
+    fn new(delay: usize, feedback: f32, damp: f32) -> Self {
+        Self {
+            buf: vec![0.0; delay.max(1)],
+            pos: 0,
+            filterstore: 0.0,
+            feedback,
+            damp,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, x: f32) -> f32 {
+        let out = self.buf[self.pos];
+        self.filterstore = out * (1.0 - self.damp) + self.filterstore * self.damp;
+        self.buf[self.pos] = x + self.filterstore * self.feedback;
+        self.pos += 1;
+        if self.pos >= self.buf.len() {
+            self.pos = 0;
+        }
+        out
+    }
+}
+
+/// Series allpass filter, as used in the Freeverb/Schroeder topology.
+struct AllpassFilter {
+    buf: std::vec::Vec<f32>,
+    pos: usize,
+}
+
+impl AllpassFilter {
+    fn new(delay: usize) -> Self {
+        Self {
+            buf: vec![0.0; delay.max(1)],
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, x: f32) -> f32 {
+        let bufout = self.buf[self.pos];
+        let out = -x + bufout;
+        self.buf[self.pos] = x + bufout * 0.5;
+        self.pos += 1;
+        if self.pos >= self.buf.len() {
+            self.pos = 0;
+        }
+        out
+    }
+}
+
+/// Classic Schroeder/Freeverb reverb: 8 parallel feedback combs summed and
+/// fed through 4 series allpasses. Unlike the time-domain convolution
+/// reverb, cost is O(1) per sample regardless of tail length, so it scales
+/// to long decays.
+struct FreeverbTank {
+    combs: std::vec::Vec<CombFilter>,
+    allpasses: std::vec::Vec<AllpassFilter>,
+    feedback: f32,
+    damp: f32,
+}
+
+impl FreeverbTank {
+    // Tuned at 44.1kHz; scaled to the actual sample rate below.
+    const COMB_DELAYS: [usize; 8] = [1557, 1617, 1491, 1422, 1277, 1356, 1188, 1116];
+    const ALLPASS_DELAYS: [usize; 4] = [225, 556, 441, 341];
+    const REFERENCE_SR: f32 = 44100.0;
+    // Classic Freeverb input scaling: 8 parallel combs near unity feedback
+    // would otherwise sum well above unity gain before the allpasses even
+    // see the signal, forcing the tank to rely on the output soft-clipper.
+    const INPUT_GAIN: f32 = 0.015;
+
+    fn new(sample_rate: f32, feedback: f32, damp: f32) -> Self {
+        let scale = sample_rate.max(1.0) / Self::REFERENCE_SR;
+        let combs = Self::COMB_DELAYS
+            .iter()
+            .map(|&d| CombFilter::new(((d as f32) * scale).round() as usize, feedback, damp))
+            .collect();
+        let allpasses = Self::ALLPASS_DELAYS
+            .iter()
+            .map(|&d| AllpassFilter::new(((d as f32) * scale).round() as usize))
+            .collect();
+        Self {
+            combs,
+            allpasses,
+            feedback,
+            damp,
+        }
+    }
+
+    fn set_room(&mut self, feedback: f32) {
+        self.feedback = feedback;
+        for c in &mut self.combs {
+            c.feedback = feedback;
+        }
+    }
+
+    fn set_damp(&mut self, damp: f32) {
+        self.damp = damp;
+        for c in &mut self.combs {
+            c.damp = damp;
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, x: f32) -> f32 {
+        let input = x * Self::INPUT_GAIN;
+        let mut out = 0.0;
+        for c in &mut self.combs {
+            out += c.process(input);
+        }
+        for a in &mut self.allpasses {
+            out = a.process(out);
+        }
+        out
+    }
+}
+
+/// Number of entries in the precomputed LFO cosine table, not counting the
+/// wraparound-duplicate last entry.
+const LFO_TABLE_SIZE: usize = 512;
+
+/// Build a `LFO_TABLE_SIZE + 1`-entry cosine lookup table (the extra entry
+/// duplicates index 0 so callers can linearly interpolate across the wrap
+/// without a branch). Read with a phase accumulator instead of calling
+/// `cos`/`sin` per sample.
+fn build_lfo_table() -> std::vec::Vec<f32> {
+    let mut table = std::vec::Vec::with_capacity(LFO_TABLE_SIZE + 1);
+    for i in 0..=LFO_TABLE_SIZE {
+        let phase = i as f32 / LFO_TABLE_SIZE as f32;
+        table.push((TAU * phase).cos());
+    }
+    table
+}
+
+/// Read the LFO table at a phase in `[0, 1)`, linearly interpolating
+/// between adjacent entries.
+#[inline]
+fn lfo_lookup(table: &[f32], phase: f32) -> f32 {
+    let pos = phase * LFO_TABLE_SIZE as f32;
+    let i0 = pos as usize;
+    let frac = pos - i0 as f32;
+    let a = table[i0];
+    let b = table[i0 + 1];
+    a + (b - a) * frac
+}
+
 const MAX_VOICES: usize = 8;
 
+/// How strongly two strings should couple through the bridge, based on how
+/// close their frequency ratio is to a simple integer ratio (unison,
+/// octave, fifth, ...). Harmonically related strings couple more; a ratio
+/// that lands far from any small p/q barely couples at all.
+fn harmonic_weight(f1: f32, f2: f32) -> f32 {
+    if f1 <= 0.0 || f2 <= 0.0 {
+        return 0.0;
+    }
+    let ratio = (f1 / f2).max(f2 / f1);
+    let mut closest = f32::MAX;
+    for q in 1..=6 {
+        for p in q..=6 {
+            let target = p as f32 / q as f32;
+            let diff = (ratio - target).abs();
+            if diff < closest {
+                closest = diff;
+            }
+        }
+    }
+    (1.0 - closest * 4.0).clamp(0.0, 1.0)
+}
+
+/// Maximum number of steps the built-in sequencer can hold.
+const MAX_SEQ_STEPS: usize = 32;
+
+/// One step of the built-in sequencer.
+#[derive(Clone, Copy)]
+struct SeqStep {
+    freq_hz: f32,
+    velocity: f32,
+    gate: bool,
+}
+
 /// Per-string voice (polyphonic Karplus–Strong)
 struct Voice {
     buffer: std::vec::Vec<f32>,
@@ -66,6 +277,11 @@ struct Voice {
     pluck_offset: usize,
     pluck_mix: f32,
     active: bool,
+
+    // --- Gated amplitude envelope (Linen-style: attack / sustain / release) ---
+    env: f32,
+    env_attack_inc: f32,
+    gate: bool,
 }
 
 impl Voice {
@@ -87,6 +303,9 @@ impl Voice {
             pluck_offset: 1,
             pluck_mix: 0.3,
             active: false,
+            env: 0.0,
+            env_attack_inc: 1.0,
+            gate: false,
         }
     }
 }
@@ -100,15 +319,52 @@ pub struct Engine {
     dispersion: f32,
     attack_decay: f32,
     guitar_type: i32,
+    // Per-sample multiplier applied to `Voice::env` while gated off; smaller
+    // is a faster (palm-muted) release, closer to 1.0 rings out longer.
+    release_coeff: f32,
     // Polyphonic voices (multiple strings)
     voices: std::vec::Vec<Voice>,
-    // Simple convolution reverb
+    // Simple convolution reverb (selected by default, gives the early
+    // reflection "body" character)
     reverb_ir: std::vec::Vec<f32>,
     reverb_buf: std::vec::Vec<f32>,
     reverb_pos: usize,
     reverb_mix: f32,
+    // Freeverb-style comb+allpass reverb (alternative, O(1)/sample tank for
+    // long tails); selectable via `engine_set_reverb_algorithm`
+    freeverb: FreeverbTank,
+    use_freeverb: bool,
     // Simple body resonators
     resonators: std::vec::Vec<Resonator>,
+
+    // --- Shared LFO section (vibrato / tremolo / chorus) ---
+    lfo_table: std::vec::Vec<f32>,
+    vibrato_rate_hz: f32,
+    vibrato_depth: f32,
+    vibrato_phase: f32,
+    tremolo_rate_hz: f32,
+    tremolo_depth: f32,
+    tremolo_phase: f32,
+    chorus_rate_hz: f32,
+    chorus_depth: f32,
+    chorus_mix: f32,
+    chorus_phase: f32,
+    chorus_buf: std::vec::Vec<f32>,
+    chorus_pos: usize,
+
+    // --- Built-in step sequencer ---
+    seq_steps: std::vec::Vec<SeqStep>,
+    seq_length: usize,
+    seq_tempo_bpm: f32,
+    seq_steps_per_beat: u32,
+    seq_playing: bool,
+    seq_step_counter: f32,
+    seq_current_step: usize,
+    seq_last_freq: f32,
+
+    // --- Sympathetic string coupling via a shared bridge bus ---
+    coupling: f32,
+    bridge_bus: f32,
 }
 
 impl Engine {
@@ -171,6 +427,21 @@ impl Engine {
             *v /= norm;
         }
         let reverb_buf = vec![0.0; ir_len];
+        let freeverb = FreeverbTank::new(sr, 0.84, 0.2);
+
+        let lfo_table = build_lfo_table();
+        // ~50ms chorus delay line: enough room for a slow, deep sweep
+        let chorus_len = ((sr * 0.05) as usize).max(8);
+        let chorus_buf = vec![0.0; chorus_len];
+
+        let seq_steps = vec![
+            SeqStep {
+                freq_hz: 110.0,
+                velocity: 0.0,
+                gate: false,
+            };
+            MAX_SEQ_STEPS
+        ];
 
         // --- Voices ---
         let mut voices = std::vec::Vec::with_capacity(MAX_VOICES);
@@ -186,12 +457,38 @@ impl Engine {
             dispersion: 0.20,
             attack_decay: 0.988,
             guitar_type: 0,
+            release_coeff: 0.9995,
             voices,
             reverb_ir,
             reverb_buf,
             reverb_pos: 0,
             reverb_mix: 0.10,
+            freeverb,
+            use_freeverb: false,
             resonators,
+            lfo_table,
+            vibrato_rate_hz: 5.0,
+            vibrato_depth: 0.0,
+            vibrato_phase: 0.0,
+            tremolo_rate_hz: 4.0,
+            tremolo_depth: 0.0,
+            tremolo_phase: 0.0,
+            chorus_rate_hz: 0.5,
+            chorus_depth: 0.0,
+            chorus_mix: 0.0,
+            chorus_phase: 0.0,
+            chorus_buf,
+            chorus_pos: 0,
+            seq_steps,
+            seq_length: 16,
+            seq_tempo_bpm: 120.0,
+            seq_steps_per_beat: 4,
+            seq_playing: false,
+            seq_step_counter: 0.0,
+            seq_current_step: 0,
+            seq_last_freq: 0.0,
+            coupling: 0.0,
+            bridge_bus: 0.0,
         };
         engine.set_guitar_profile(0);
         engine
@@ -250,6 +547,14 @@ impl Engine {
 
     #[inline]
     fn process_reverb(&mut self, x: f32) -> f32 {
+        if self.use_freeverb {
+            return self.freeverb.process(x);
+        }
+        self.process_convolution_reverb(x)
+    }
+
+    #[inline]
+    fn process_convolution_reverb(&mut self, x: f32) -> f32 {
         if self.reverb_ir.is_empty() || self.reverb_buf.is_empty() {
             return x;
         }
@@ -380,6 +685,94 @@ impl Engine {
 
         voice.level = vel.abs();
         voice.active = true;
+
+        // --- Envelope: open the gate and ramp in over a short attack ---
+        let attack_s = 0.003;
+        voice.env = 0.0;
+        voice.env_attack_inc = 1.0 / (sr * attack_s).max(1.0);
+        voice.gate = true;
+    }
+
+    /// Release a gated voice: flips the gate so `render` ramps `env` down
+    /// by `release_coeff` each sample until the voice goes silent.
+    fn note_off(&mut self, freq: f32) {
+        let f = freq.max(20.0);
+        let mut best_i: Option<usize> = None;
+        let mut best_diff = f32::MAX;
+        for (i, v) in self.voices.iter().enumerate() {
+            if v.active && v.gate {
+                let diff = (v.freq_hz - f).abs();
+                if diff < best_diff {
+                    best_diff = diff;
+                    best_i = Some(i);
+                }
+            }
+        }
+        if let Some(i) = best_i {
+            self.voices[i].gate = false;
+        }
+    }
+
+    /// Advance the sequencer by one sample, firing the current step's
+    /// `excite`/`note_off` when a step boundary is crossed so a whole
+    /// phrase can play from a single `render` loop.
+    fn tick_sequencer(&mut self, sr: f32) {
+        if !self.seq_playing || self.seq_steps.is_empty() || self.seq_length == 0 {
+            return;
+        }
+
+        if self.seq_step_counter <= 0.0 {
+            if self.seq_last_freq > 0.0 {
+                self.note_off(self.seq_last_freq);
+            }
+
+            let step = self.seq_steps[self.seq_current_step % self.seq_length];
+            if step.gate {
+                self.excite(step.freq_hz, step.velocity);
+                self.seq_last_freq = step.freq_hz;
+            } else {
+                self.seq_last_freq = 0.0;
+            }
+
+            self.seq_current_step = (self.seq_current_step + 1) % self.seq_length;
+
+            let steps_per_sec = (self.seq_tempo_bpm / 60.0) * self.seq_steps_per_beat as f32;
+            let samples_per_step = sr / steps_per_sec.max(1.0e-3);
+            self.seq_step_counter += samples_per_step;
+        }
+
+        self.seq_step_counter -= 1.0;
+    }
+
+    fn seq_set_tempo(&mut self, bpm: f32) {
+        self.seq_tempo_bpm = bpm.clamp(1.0, 400.0);
+    }
+
+    fn seq_set_step(&mut self, index: usize, freq: f32, velocity: f32, gate: bool) {
+        if let Some(step) = self.seq_steps.get_mut(index) {
+            step.freq_hz = freq.max(20.0);
+            step.velocity = velocity.clamp(0.0, 1.0);
+            step.gate = gate;
+        }
+    }
+
+    fn seq_set_length(&mut self, n: usize) {
+        self.seq_length = n.clamp(1, self.seq_steps.len());
+        self.seq_current_step %= self.seq_length;
+    }
+
+    fn seq_play(&mut self) {
+        self.seq_playing = true;
+        self.seq_current_step = 0;
+        self.seq_step_counter = 0.0;
+    }
+
+    fn seq_stop(&mut self) {
+        self.seq_playing = false;
+        if self.seq_last_freq > 0.0 {
+            self.note_off(self.seq_last_freq);
+            self.seq_last_freq = 0.0;
+        }
     }
 
     fn render(&mut self, out: &mut [f32]) {
@@ -393,13 +786,56 @@ impl Engine {
         let base_brightness = self.brightness;
         let base_dispersion = self.dispersion;
         let base_attack_decay = self.attack_decay;
+        let release_coeff = self.release_coeff;
         let mix = self.reverb_mix;
+        let sr = self.sample_rate.max(1.0);
+
+        // How far vibrato may nudge the fractional delay, in samples; kept
+        // small so pitch wobble stays musical rather than warbly.
+        const VIBRATO_MAX_OFFSET: f32 = 0.4;
+        // Chorus tap sweeps +/- this many samples around its base delay.
+        const CHORUS_BASE_DELAY: f32 = 12.0;
+        const CHORUS_MOD_RANGE: f32 = 10.0;
+        // Fraction of each voice's bridge output fed into the shared bus;
+        // kept small since `coupling` (not this) is the user-facing knob.
+        const BRIDGE_SEND_GAIN: f32 = 0.2;
+        // See the coupling-injection comment in the voice loop below: a
+        // small gain plus a hard clamp on the written sample keeps the
+        // bridge bus a coloration rather than a second feedback path.
+        const COUPLING_INJECT_GAIN: f32 = 0.015;
+        const LOOP_SAFETY_LIMIT: f32 = 1.0;
 
         for s in out.iter_mut() {
+            // --- Shared LFOs: sample the current phase, then advance it ---
+            let vib_val = lfo_lookup(&self.lfo_table, self.vibrato_phase);
+            let trem_val = lfo_lookup(&self.lfo_table, self.tremolo_phase);
+            let cho_val = lfo_lookup(&self.lfo_table, self.chorus_phase);
+            self.vibrato_phase = (self.vibrato_phase + self.vibrato_rate_hz / sr).fract();
+            self.tremolo_phase = (self.tremolo_phase + self.tremolo_rate_hz / sr).fract();
+            self.chorus_phase = (self.chorus_phase + self.chorus_rate_hz / sr).fract();
+            let vibrato_offset = vib_val * self.vibrato_depth * VIBRATO_MAX_OFFSET;
+
+            self.tick_sequencer(sr);
+
+            // Bridge bus accumulated from last sample's active voices; fed
+            // back into every other voice's delay line this sample.
+            let injected_bus = self.bridge_bus;
+            self.bridge_bus = 0.0;
+            let coupling = self.coupling;
+
+            let mut active_freqs = [0.0f32; MAX_VOICES];
+            let mut active_flags = [false; MAX_VOICES];
+            for (vi, v) in self.voices.iter().enumerate() {
+                if v.active && v.buffer_len >= 2 {
+                    active_freqs[vi] = v.freq_hz;
+                    active_flags[vi] = true;
+                }
+            }
+
             let mut string_sum: f32 = 0.0;
             let mut active_count: f32 = 0.0;
 
-            for voice in &mut self.voices {
+            for (vi, voice) in self.voices.iter_mut().enumerate() {
                 if !voice.active || voice.buffer_len < 2 {
                     continue;
                 }
@@ -424,13 +860,31 @@ impl Engine {
                     (base_attack_decay - 0.004 * f_norm).clamp(0.97, 0.993);
 
                 let curr = voice.buffer[i];
-                let next = voice.buffer[j];
-                let interp = curr + (next - curr) * voice.frac_delay;
+                // Vibrato modulates the *whole* read position, not just the
+                // fractional residue — clamping only `frac_delay` would
+                // rectify the wobble into a DC-biased half-wave whenever it
+                // sits near 0 or 1. Split the modulated position back into
+                // an integer shift (wrapped into the buffer) and a fresh
+                // fraction in [0, 1) so the sweep crosses sample boundaries
+                // symmetrically.
+                let read_pos = voice.frac_delay + vibrato_offset;
+                let shift = read_pos.floor();
+                let d = read_pos - shift;
+                let shifted_i = (i as isize + shift as isize).rem_euclid(len as isize) as usize;
+                let interp = cubic_hermite(&voice.buffer, shifted_i, len, d);
 
                 // moyenne classique KS
                 let avg = 0.5 * (interp + curr);
 
                 // --- All-pass pour dispersion (inharmonicité légère) ---
+                // This is a first-order *state* all-pass (single scalar
+                // feedback/feedforward on `ap_x1`/`ap_y1`), not a
+                // fractional-delay read over a buffer — it has no
+                // `frac_delay`-like read position for `cubic_hermite` to
+                // interpolate, so there's nothing to reuse it on here. Its
+                // tuning is still consistent with the cubic read above: it
+                // operates on `avg`, which already mixes in the cubic
+                // `interp` tap rather than the old linear one.
                 let a = dispersion;
                 let ap = -a * avg + voice.ap_x1 + a * voice.ap_y1;
                 voice.ap_x1 = avg;
@@ -442,10 +896,44 @@ impl Engine {
                 // --- Brightness : mix entre chemin brillant et chemin filtré ---
                 let y = decay * (brightness * ap + (1.0 - brightness) * voice.lp_state);
 
-                // feedback dans le buffer
-                voice.buffer[voice.buffer_idx] = y;
+                // --- Sympathetic coupling: inject the shared bridge bus,
+                // weighted by how harmonically related this voice is to
+                // the other currently-sounding voices ---
+                let mut weight_sum = 0.0f32;
+                let mut weight_count = 0.0f32;
+                for (oi, &of) in active_freqs.iter().enumerate() {
+                    if oi != vi && active_flags[oi] {
+                        weight_sum += harmonic_weight(f, of);
+                        weight_count += 1.0;
+                    }
+                }
+                let coupling_weight = if weight_count > 0.0 {
+                    weight_sum / weight_count
+                } else {
+                    0.0
+                };
+
+                // feedback dans le buffer — the coupling injection sits
+                // inside this near-unity-gain KS loop, where several
+                // strongly-coupled voices (e.g. a root/fifth/octave chord)
+                // can pump energy into each other even when each voice's
+                // own `decay` alone is stable. A small injection gain keeps
+                // normal coupling subtle, and hard-clamping the value that
+                // actually lands in the delay line is the backstop that
+                // guarantees the loop can never escape into `inf`/`NaN`
+                // regardless of how many voices couple or how they resonate.
+                // Leave out this voice's own send from last sample so only
+                // the other strings drive it.
+                let others_bus = injected_bus - voice.bridge_state * BRIDGE_SEND_GAIN;
+                let injection = others_bus * coupling * coupling_weight * COUPLING_INJECT_GAIN;
+                voice.buffer[voice.buffer_idx] =
+                    (y + injection).clamp(-LOOP_SAFETY_LIMIT, LOOP_SAFETY_LIMIT);
                 voice.buffer_idx = j;
 
+                // Feed this voice's bridge output into the bus for next sample.
+                voice.bridge_state = y;
+                self.bridge_bus += voice.bridge_state * BRIDGE_SEND_GAIN;
+
                 voice.level = 0.997 * voice.level + 0.003 * y.abs();
 
                 let mut sample = y;
@@ -459,7 +947,20 @@ impl Engine {
                     voice.attack_level *= attack_decay;
                 }
 
-                if voice.level < 5.0e-6 && voice.attack_level < 5.0e-5 {
+                // --- Gated amplitude envelope (attack / sustain / release) ---
+                if voice.gate {
+                    if voice.env < 1.0 {
+                        voice.env = (voice.env + voice.env_attack_inc).min(1.0);
+                    }
+                } else {
+                    voice.env *= release_coeff;
+                    if voice.env < 1.0e-4 {
+                        voice.env = 0.0;
+                    }
+                }
+                sample *= voice.env;
+
+                if (voice.level < 5.0e-6 && voice.attack_level < 5.0e-5) || voice.env <= 0.0 {
                     voice.active = false;
                 }
 
@@ -470,6 +971,10 @@ impl Engine {
             let norm = active_count.max(1.0);
             string_sum /= norm;
 
+            // --- Tremolo: amplitude-modulate the summed strings ---
+            let trem_gain = 1.0 - self.tremolo_depth + self.tremolo_depth * (0.5 * (trem_val + 1.0));
+            string_sum *= trem_gain;
+
             // Pass through simple body resonators
             let mut body = 0.0;
             for r in &mut self.resonators {
@@ -477,7 +982,26 @@ impl Engine {
             }
 
             // Mix dry string and body response, then reverb
-            let dry = 0.55 * string_sum + 0.45 * body;
+            let mut dry = 0.55 * string_sum + 0.45 * body;
+
+            // --- Chorus: a second modulated-delay tap of the dry signal ---
+            {
+                let clen = self.chorus_buf.len();
+                self.chorus_buf[self.chorus_pos] = dry;
+                let delay = (CHORUS_BASE_DELAY + cho_val * self.chorus_depth * CHORUS_MOD_RANGE)
+                    .clamp(1.0, (clen - 2) as f32);
+                let read_pos = self.chorus_pos as f32 - delay;
+                let read_pos = ((read_pos % clen as f32) + clen as f32) % clen as f32;
+                let i0 = read_pos as usize;
+                let frac = read_pos - i0 as f32;
+                let v0 = self.chorus_buf[i0];
+                let v1 = self.chorus_buf[(i0 + 1) % clen];
+                let chorus_tap = v0 + (v1 - v0) * frac;
+                self.chorus_pos = (self.chorus_pos + 1) % clen;
+
+                dry += chorus_tap * self.chorus_mix;
+            }
+
             let wet = self.process_reverb(dry);
             let mut out_sample = dry * (1.0 - mix) + wet * mix;
 
@@ -518,6 +1042,26 @@ pub extern "C" fn engine_note_on(engine: *mut Engine, freq: f32, velocity: f32)
     engine.excite(freq, velocity);
 }
 
+#[no_mangle]
+pub extern "C" fn engine_note_off(engine: *mut Engine, freq: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.note_off(freq);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_release(engine: *mut Engine, rate: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    // Per-sample decay multiplier applied while gated off: lower is a
+    // damped/palm-muted release, closer to 1.0 lets the note ring out.
+    engine.release_coeff = rate.clamp(0.9, 0.99999);
+}
+
 #[no_mangle]
 pub extern "C" fn engine_set_decay(engine: *mut Engine, decay: f32) {
     if engine.is_null() {
@@ -565,6 +1109,125 @@ pub extern "C" fn engine_set_reverb_mix(engine: *mut Engine, mix: f32) {
     engine.reverb_mix = mix.clamp(0.0, 0.9);
 }
 
+#[no_mangle]
+pub extern "C" fn engine_set_reverb_algorithm(engine: *mut Engine, algorithm: i32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    // 0 = convolution (early-reflection "body" character), 1 = Freeverb tank
+    engine.use_freeverb = algorithm == 1;
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_reverb_room(engine: *mut Engine, feedback: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.freeverb.set_room(feedback.clamp(0.0, 0.99));
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_reverb_damp(engine: *mut Engine, damp: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.freeverb.set_damp(damp.clamp(0.0, 1.0));
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_vibrato(engine: *mut Engine, rate_hz: f32, depth: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.vibrato_rate_hz = rate_hz.clamp(0.0, 20.0);
+    engine.vibrato_depth = depth.clamp(0.0, 1.0);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_tremolo(engine: *mut Engine, rate_hz: f32, depth: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.tremolo_rate_hz = rate_hz.clamp(0.0, 20.0);
+    engine.tremolo_depth = depth.clamp(0.0, 1.0);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_chorus(engine: *mut Engine, rate_hz: f32, depth: f32, mix: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.chorus_rate_hz = rate_hz.clamp(0.0, 20.0);
+    engine.chorus_depth = depth.clamp(0.0, 1.0);
+    engine.chorus_mix = mix.clamp(0.0, 1.0);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_seq_set_tempo(engine: *mut Engine, bpm: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.seq_set_tempo(bpm);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_seq_set_step(
+    engine: *mut Engine,
+    index: usize,
+    freq: f32,
+    velocity: f32,
+    gate: i32,
+) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.seq_set_step(index, freq, velocity, gate != 0);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_seq_set_length(engine: *mut Engine, n: usize) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.seq_set_length(n);
+}
+
+#[no_mangle]
+pub extern "C" fn engine_seq_play(engine: *mut Engine) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.seq_play();
+}
+
+#[no_mangle]
+pub extern "C" fn engine_seq_stop(engine: *mut Engine) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.seq_stop();
+}
+
+#[no_mangle]
+pub extern "C" fn engine_set_coupling(engine: *mut Engine, amount: f32) {
+    if engine.is_null() {
+        return;
+    }
+    let engine = unsafe { &mut *engine };
+    engine.coupling = amount.clamp(0.0, 1.0);
+}
+
 #[no_mangle]
 pub extern "C" fn engine_render(engine: *mut Engine, buffer: *mut f32, frames: usize) {
     if engine.is_null() || buffer.is_null() || frames == 0 {
@@ -585,3 +1248,28 @@ pub extern "C" fn alloc_buffer(frames: usize) -> *mut f32 {
     std::mem::forget(buf); // leak on purpose for WASM lifetime
     ptr
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sympathetic_coupling_stays_finite_and_bounded() {
+        let mut engine = Engine::new(44100.0);
+        engine.coupling = 1.0;
+
+        // Root, fifth, and octave: the most harmonically-related chord, and
+        // so the worst case for the bridge bus driving positive feedback.
+        engine.excite(220.0, 0.9);
+        engine.excite(330.0, 0.9);
+        engine.excite(440.0, 0.9);
+
+        let mut buf = vec![0.0f32; 44100];
+        engine.render(&mut buf);
+
+        for &s in &buf {
+            assert!(s.is_finite(), "non-finite sample in sustained chord: {s}");
+            assert!(s.abs() <= 5.0, "sample out of bounds: {s}");
+        }
+    }
+}